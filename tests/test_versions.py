@@ -0,0 +1,111 @@
+"""版本格式识别和最新版本选择，用真实项目的标签检查
+
+运行: python3 -m unittest discover -s tests
+"""
+
+import os
+import unittest
+from importlib.machinery import SourceFileLoader
+from importlib.util import module_from_spec, spec_from_loader
+
+SCRIPT = os.path.join(os.path.dirname(__file__), os.pardir, "appimage-finder")
+loader = SourceFileLoader("appimage_finder", SCRIPT)
+finder = module_from_spec(spec_from_loader(loader.name, loader))
+loader.exec_module(finder)
+
+
+def release(repo, tag, published_at, name="App-x86_64.AppImage"):
+    item = {
+        "repo": repo,
+        "architecture": "x86_64",
+        "asset_type": "appimage",
+        "tag_name": tag,
+        "published_at": published_at,
+        "download_url": f"https://github.com/{repo}/releases/download/{tag}/{name}",
+        "version_raw": finder.extract_version_raw(tag, name),
+    }
+    item["version_scheme"] = finder.version_scheme(item["version_raw"])
+    return item
+
+
+class VersionSchemeTest(unittest.TestCase):
+    def test_calver_tags(self):
+        for tag in (
+            "v24.02.0",  # Kdenlive
+            "v23.08.5",
+            "24.04",
+            "2024.03.10",  # yt-dlp
+            "weekly-2024.03.27",  # FreeCAD 每周构建
+            "20240315",
+        ):
+            with self.subTest(tag=tag):
+                self.assertEqual(finder.version_scheme(tag), "calver")
+
+    def test_semver_tags(self):
+        for tag in (
+            "30.0.2",  # OBS Studio
+            "31.0.0-beta1",
+            "12.10.1",
+            "v1.5.8",  # Obsidian
+            "v2.14.20",  # Joplin
+        ):
+            with self.subTest(tag=tag):
+                self.assertEqual(finder.version_scheme(tag), "semver")
+
+    def test_calver_key(self):
+        cases = {
+            "v24.02.0": (2024, 2, 0),
+            "2024.03.10": (2024, 3, 10),
+            "weekly-2024.03.27": (2024, 3, 27),
+            "20240315": (2024, 3, 15),
+        }
+        for tag, key in cases.items():
+            with self.subTest(tag=tag):
+                item = {"version_raw": tag, "version_scheme": "calver"}
+                self.assertEqual(finder.calver_key(item), key)
+
+    def test_extract_version(self):
+        cases = {
+            ("v2.0", "App.AppImage"): "2.0.0.0",
+            ("24.04", "App.AppImage"): "24.4.0.0",
+            ("20240315", "App.AppImage"): "2024.3.15.0",
+            ("v1.5.8", "Obsidian-1.5.8.AppImage"): "1.5.8.0",
+            ("nightly", "App-2.1-x86_64.AppImage"): "2.1.0.0",
+            ("continuous", "App-qt5.15-x86_64.AppImage"): "1.0.0.0",
+        }
+        for (tag, name), version in cases.items():
+            with self.subTest(tag=tag, name=name):
+                self.assertEqual(finder.extract_version_4digit(tag, name), version)
+
+
+class LatestVersionTest(unittest.TestCase):
+    def test_calver_patch_released_later(self):
+        # 24.02.0 之后又发布了 23.08.5 补丁版本，最新的仍是 24.02.0
+        items = [
+            release("KDE/kdenlive", "v24.02.0", "2024-03-06T10:00:00Z"),
+            release("KDE/kdenlive", "v23.08.5", "2024-03-07T10:00:00Z"),
+        ]
+        (latest,) = finder.keep_latest_versions(items)
+        self.assertEqual(latest["tag_name"], "v24.02.0")
+
+    def test_semver_uses_publish_time(self):
+        # 普通版本号按发布时间，29.1.4 是在 30.0.0 之后发布的
+        items = [
+            release("obsproject/obs-studio", "30.0.0", "2023-11-12T00:00:00Z"),
+            release("obsproject/obs-studio", "29.1.4", "2023-11-20T00:00:00Z"),
+        ]
+        (latest,) = finder.keep_latest_versions(items)
+        self.assertEqual(latest["tag_name"], "29.1.4")
+
+    def test_nightly_dates(self):
+        items = [
+            release("FreeCAD/FreeCAD", "weekly-2024.03.27", "2024-03-27T06:00:00Z"),
+            release("FreeCAD/FreeCAD", "weekly-2024.04.03", "2024-04-03T06:00:00Z"),
+            release("FreeCAD/FreeCAD", "weekly-2024.03.20", "2024-04-05T06:00:00Z"),
+        ]
+        (latest,) = finder.keep_latest_versions(items)
+        self.assertEqual(latest["tag_name"], "weekly-2024.04.03")
+
+
+if __name__ == "__main__":
+    unittest.main()